@@ -4,23 +4,75 @@
 
 use {
     crate::transaction_batch::TransactionBatch,
+    futures::future::join_all,
     log::*,
     lru::LruCache,
-    std::net::SocketAddr,
+    std::{
+        collections::HashMap,
+        future::Future,
+        net::SocketAddr,
+        pin::Pin,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    },
     thiserror::Error,
     tokio::{
-        sync::mpsc::{self, error::TrySendError},
+        sync::{
+            mpsc::{self, error::TrySendError},
+            oneshot, watch,
+        },
         task::JoinHandle,
     },
     tokio_util::sync::CancellationToken,
 };
 
+/// Operator-tunable knobs for a running [`WorkersCache`], published through a
+/// [`watch::Sender`] so the whole fleet can be retuned without a restart.
+#[derive(Debug, Clone, Copy)]
+pub struct WorkersConfig {
+    /// Maximum number of cached workers. Shrinking evicts the least recently
+    /// used workers.
+    pub lru_capacity: usize,
+    /// Target depth of a worker's transaction-batch channel. Live workers
+    /// observe this through [`WorkerInfo::current_channel_capacity`] the next
+    /// time they check backpressure, since a `watch` channel always retains
+    /// only the latest value.
+    pub worker_channel_capacity: usize,
+}
+
+/// Send-side counters accumulated by a worker task over its lifetime and
+/// reported back to [`WorkersCache`] on shutdown.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WorkerStats {
+    pub batches_sent: u64,
+    pub transactions_sent: u64,
+    pub full_channel_drops: u64,
+    pub receiver_dropped_drops: u64,
+    pub bytes_sent: u64,
+}
+
+impl WorkerStats {
+    fn merge(&mut self, other: &WorkerStats) {
+        self.batches_sent += other.batches_sent;
+        self.transactions_sent += other.transactions_sent;
+        self.full_channel_drops += other.full_channel_drops;
+        self.receiver_dropped_drops += other.receiver_dropped_drops;
+        self.bytes_sent += other.bytes_sent;
+    }
+}
+
+/// Fleet-wide [`WorkerStats`] totals, shared between [`WorkersCache`] and the
+/// detached tasks spawned by [`maybe_shutdown_worker`].
+pub type SharedWorkerStats = Arc<Mutex<WorkerStats>>;
+
 /// [`WorkerInfo`] holds information about a worker responsible for sending
 /// transaction batches.
 pub struct WorkerInfo {
     sender: mpsc::Sender<TransactionBatch>,
     handle: JoinHandle<()>,
     cancel: CancellationToken,
+    config: watch::Receiver<WorkersConfig>,
+    stats: oneshot::Receiver<WorkerStats>,
 }
 
 impl WorkerInfo {
@@ -28,14 +80,26 @@ impl WorkerInfo {
         sender: mpsc::Sender<TransactionBatch>,
         handle: JoinHandle<()>,
         cancel: CancellationToken,
+        config: watch::Receiver<WorkersConfig>,
+        stats: oneshot::Receiver<WorkerStats>,
     ) -> Self {
         Self {
             sender,
             handle,
             cancel,
+            config,
+            stats,
         }
     }
 
+    /// Returns the currently published `worker_channel_capacity`, for workers
+    /// that want to make backpressure decisions against the latest
+    /// operator-configured target rather than the value in effect at
+    /// construction time.
+    pub fn current_channel_capacity(&self) -> usize {
+        self.config.borrow().worker_channel_capacity
+    }
+
     fn try_send_transactions(&self, txs_batch: TransactionBatch) -> Result<(), WorkersCacheError> {
         self.sender.try_send(txs_batch).map_err(|err| match err {
             TrySendError::Full(_) => WorkersCacheError::FullChannel,
@@ -44,6 +108,21 @@ impl WorkerInfo {
         Ok(())
     }
 
+    /// Attempts to reserve a slot in the worker's channel without blocking,
+    /// returning an owned [`WorkerPermit`] that later consumes the reservation
+    /// with [`WorkerPermit::send`]. Unlike [`Self::try_send_transactions`],
+    /// holding the permit guarantees the eventual send cannot fail because the
+    /// channel is full.
+    fn try_reserve(&self) -> Result<mpsc::OwnedPermit<TransactionBatch>, WorkersCacheError> {
+        self.sender
+            .clone()
+            .try_reserve_owned()
+            .map_err(|err| match err {
+                TrySendError::Full(_) => WorkersCacheError::FullChannel,
+                TrySendError::Closed(_) => WorkersCacheError::ReceiverDropped,
+            })
+    }
+
     async fn send_transactions(
         &self,
         txs_batch: TransactionBatch,
@@ -57,24 +136,191 @@ impl WorkerInfo {
 
     /// Closes the worker by dropping the sender and awaiting the worker's
     /// statistics.
-    async fn shutdown(self) -> Result<(), WorkersCacheError> {
+    async fn shutdown(self) -> Result<WorkerStats, WorkersCacheError> {
         self.cancel.cancel();
         drop(self.sender);
         self.handle
             .await
             .map_err(|_| WorkersCacheError::TaskJoinFailure)?;
-        Ok(())
+        self.stats
+            .await
+            .map_err(|_| WorkersCacheError::TaskJoinFailure)
+    }
+
+    /// Stops accepting new work by dropping the sender, then waits up to
+    /// `drain_timeout` for the worker to flush whatever batches are already
+    /// queued in its channel. If the deadline elapses, fires the
+    /// cancellation token to force-stop the straggler before awaiting it to
+    /// completion.
+    async fn drain(self, drain_timeout: Duration) -> DrainOutcome {
+        let Self {
+            sender,
+            mut handle,
+            cancel,
+            stats,
+            ..
+        } = self;
+        drop(sender);
+
+        let (join_result, force_cancelled) =
+            match tokio::time::timeout(drain_timeout, &mut handle).await {
+                Ok(join_result) => (join_result, false),
+                Err(_) => {
+                    cancel.cancel();
+                    (handle.await, true)
+                }
+            };
+
+        // Only collect stats for workers that finished on their own: a
+        // force-cancelled worker may never reach the point where it sends
+        // its `WorkerStats`, and awaiting it here would hang past the
+        // deadline this method is supposed to enforce.
+        let stats = match (join_result, force_cancelled) {
+            (Ok(()), false) => stats.await.ok(),
+            _ => None,
+        };
+
+        DrainOutcome {
+            stats,
+            force_cancelled,
+        }
+    }
+}
+
+/// Outcome of a single worker's [`WorkerInfo::drain`] call.
+struct DrainOutcome {
+    stats: Option<WorkerStats>,
+    force_cancelled: bool,
+}
+
+/// A previously reserved slot in a worker's channel, obtained through
+/// [`WorkersCache::reserve_for`]. Holding a [`WorkerPermit`] guarantees that
+/// the eventual [`WorkerPermit::send`] cannot fail because the channel is
+/// full.
+#[derive(Debug)]
+pub struct WorkerPermit {
+    permit: mpsc::OwnedPermit<TransactionBatch>,
+}
+
+impl WorkerPermit {
+    fn new(permit: mpsc::OwnedPermit<TransactionBatch>) -> Self {
+        Self { permit }
+    }
+
+    /// Consumes the reserved slot, handing `txs_batch` off to the worker.
+    pub fn send(self, txs_batch: TransactionBatch) {
+        self.permit.send(txs_batch);
+    }
+}
+
+/// Builds a fresh [`WorkerInfo`] for a peer, used by [`RespawnPolicy`] to
+/// reconnect a worker after its receiver has been dropped.
+pub type WorkerFactory =
+    Arc<dyn Fn(SocketAddr) -> Pin<Box<dyn Future<Output = WorkerInfo> + Send>> + Send + Sync>;
+
+/// Opt-in policy controlling automatic worker respawn after
+/// [`WorkersCacheError::ReceiverDropped`], with exponential backoff so a burst
+/// of failed sends to a dead endpoint doesn't hammer it with reconnects.
+pub struct RespawnPolicy {
+    factory: WorkerFactory,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_attempts: u32,
+}
+
+impl RespawnPolicy {
+    pub fn new(
+        factory: WorkerFactory,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            factory,
+            initial_backoff,
+            max_backoff,
+            max_attempts,
+        }
+    }
+}
+
+/// Per-peer backoff bookkeeping maintained by [`WorkersCache::maybe_respawn`].
+struct RespawnState {
+    last_attempt: Instant,
+    backoff: Duration,
+    attempts: u32,
+}
+
+/// Doubles `current` for the next respawn attempt, capped at `max_backoff`.
+fn next_backoff(current: Duration, max_backoff: Duration) -> Duration {
+    (current * 2).min(max_backoff)
+}
+
+/// A small pool of [`WorkerInfo`] tasks serving the same leader. Spreading a
+/// hot leader's batches across a fixed fan-out of independent
+/// connections/streams avoids a single `mpsc` channel becoming the
+/// throughput bottleneck.
+struct WorkerPool {
+    workers: Vec<WorkerInfo>,
+    next: usize,
+}
+
+impl WorkerPool {
+    fn new(workers: Vec<WorkerInfo>) -> Self {
+        Self { workers, next: 0 }
+    }
+
+    fn len(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Picks the worker with the most spare channel capacity, breaking ties
+    /// by round-robin, the way a fixed-size runner pool spreads queued jobs
+    /// across its workers. Returns `None` if the pool is currently empty.
+    fn least_loaded(&mut self) -> Option<usize> {
+        let max_capacity = self
+            .workers
+            .iter()
+            .map(|worker| worker.sender.capacity())
+            .max()?;
+        let candidates: Vec<usize> = self
+            .workers
+            .iter()
+            .enumerate()
+            .filter(|(_, worker)| worker.sender.capacity() == max_capacity)
+            .map(|(index, _)| index)
+            .collect();
+        let chosen = candidates[self.next % candidates.len()];
+        self.next = self.next.wrapping_add(1);
+        Some(chosen)
     }
 }
 
 /// [`WorkersCache`] manages and caches workers. It uses an LRU cache to store and
 /// manage workers. It also tracks transaction statistics for each peer.
 pub struct WorkersCache {
-    workers: LruCache<SocketAddr, WorkerInfo>,
+    workers: LruCache<SocketAddr, WorkerPool>,
 
     /// Indicates that the `WorkersCache` is been `shutdown()`, interrupting any outstanding
     /// `send_transactions_to_address()` invocations.
     cancel: CancellationToken,
+
+    /// Latest operator-published [`WorkersConfig`], observed through
+    /// [`Self::apply_config_updates`] to retune the cache at runtime.
+    config: watch::Receiver<WorkersConfig>,
+
+    /// Opt-in automatic respawn policy, see [`Self::set_respawn_policy`].
+    respawn: Option<RespawnPolicy>,
+
+    /// Per-peer backoff state, populated lazily by [`Self::maybe_respawn`].
+    respawn_state: HashMap<SocketAddr, RespawnState>,
+
+    /// Fleet-wide send statistics, folded in as workers are shut down. See
+    /// [`Self::drain_stats`].
+    totals: SharedWorkerStats,
+
+    /// Fixed number of workers kept per leader.
+    fan_out: usize,
 }
 
 #[derive(Debug, Error, PartialEq)]
@@ -94,36 +340,154 @@ pub enum WorkersCacheError {
 }
 
 impl WorkersCache {
-    pub(crate) fn new(capacity: usize, cancel: CancellationToken) -> Self {
+    pub(crate) fn new(
+        config: watch::Receiver<WorkersConfig>,
+        cancel: CancellationToken,
+        fan_out: usize,
+    ) -> Self {
+        let lru_capacity = config.borrow().lru_capacity;
         Self {
-            workers: LruCache::new(capacity),
+            workers: LruCache::new(lru_capacity),
             cancel,
+            config,
+            respawn: None,
+            respawn_state: HashMap::new(),
+            totals: Arc::new(Mutex::new(WorkerStats::default())),
+            fan_out,
         }
     }
 
+    /// Returns the fleet-wide [`WorkerStats`] accumulated so far, resetting
+    /// the running totals back to zero.
+    pub fn drain_stats(&self) -> WorkerStats {
+        let mut totals = self.totals.lock().expect("stats mutex poisoned");
+        std::mem::take(&mut *totals)
+    }
+
+    /// Enables automatic worker respawn: once a peer's worker is evicted after
+    /// [`WorkersCacheError::ReceiverDropped`], [`Self::maybe_respawn`] will
+    /// recreate it via `policy`'s factory, honoring per-peer exponential
+    /// backoff.
+    pub fn set_respawn_policy(&mut self, policy: RespawnPolicy) {
+        self.respawn = Some(policy);
+    }
+
+    /// If a respawn policy is configured and `peer`'s pool is short of the
+    /// configured fan-out, attempts to spawn the missing workers, provided
+    /// enough time has elapsed since the last attempt and the per-peer retry
+    /// budget isn't exhausted.
+    ///
+    /// Returns `true` if at least one worker was spawned and added to the
+    /// pool.
+    ///
+    /// This is not called automatically on eviction: callers that observe
+    /// [`WorkersCacheError::ReceiverDropped`] from
+    /// [`Self::try_send_transactions_to_address`], [`Self::reserve_for`], or
+    /// [`Self::send_transactions_to_address`] are expected to follow up with
+    /// `maybe_respawn(peer)` themselves to rebuild the pool; otherwise it
+    /// stays short of `fan_out` until something else triggers a respawn.
+    pub async fn maybe_respawn(&mut self, peer: SocketAddr) -> bool {
+        let current_len = self.workers.get(&peer).map_or(0, WorkerPool::len);
+        if current_len >= self.fan_out {
+            return false;
+        }
+        let Some(policy) = self.respawn.as_ref() else {
+            return false;
+        };
+
+        let now = Instant::now();
+        let state = self
+            .respawn_state
+            .entry(peer)
+            .or_insert_with(|| RespawnState {
+                last_attempt: now - policy.initial_backoff,
+                backoff: policy.initial_backoff,
+                attempts: 0,
+            });
+
+        if state.attempts >= policy.max_attempts {
+            return false;
+        }
+        if now.duration_since(state.last_attempt) < state.backoff {
+            return false;
+        }
+
+        state.last_attempt = now;
+        state.attempts += 1;
+        state.backoff = next_backoff(state.backoff, policy.max_backoff);
+
+        let mut spawned = Vec::with_capacity(self.fan_out - current_len);
+        for _ in current_len..self.fan_out {
+            spawned.push((policy.factory)(peer).await);
+        }
+
+        if let Some(pool) = self.workers.get_mut(&peer) {
+            pool.workers.extend(spawned);
+        } else {
+            maybe_shutdown_worker_pool(self.push(peer, spawned), self.totals.clone());
+        }
+        self.respawn_state.remove(&peer);
+        true
+    }
+
+    /// Waits for a new [`WorkersConfig`] to be published on the watch channel
+    /// and applies it, resizing the LRU cache and evicting any pools that no
+    /// longer fit through the usual [`ShutdownWorkerPool`] path.
+    ///
+    /// Meant to be polled in the same select loop that drives incoming
+    /// transaction batches.
+    pub async fn apply_config_updates(&mut self) -> Result<(), WorkersCacheError> {
+        self.config
+            .changed()
+            .await
+            .map_err(|_| WorkersCacheError::ShutdownError)?;
+        let lru_capacity = self.config.borrow_and_update().lru_capacity;
+        self.resize(lru_capacity);
+        Ok(())
+    }
+
+    fn resize(&mut self, lru_capacity: usize) {
+        while self.workers.len() > lru_capacity {
+            maybe_shutdown_worker_pool(
+                self.workers
+                    .pop_lru()
+                    .map(|(leader, pool)| ShutdownWorkerPool {
+                        leader,
+                        workers: pool.workers,
+                    }),
+                self.totals.clone(),
+            );
+        }
+        self.workers.resize(lru_capacity);
+    }
+
     pub fn contains(&self, peer: &SocketAddr) -> bool {
         self.workers.contains(peer)
     }
 
+    /// Pushes the full pool of workers for `leader` into the cache, evicting
+    /// the least recently used peer's pool if the cache is at capacity.
     pub(crate) fn push(
         &mut self,
         leader: SocketAddr,
-        peer_worker: WorkerInfo,
-    ) -> Option<ShutdownWorker> {
-        if let Some((leader, popped_worker)) = self.workers.push(leader, peer_worker) {
-            return Some(ShutdownWorker {
+        peer_workers: Vec<WorkerInfo>,
+    ) -> Option<ShutdownWorkerPool> {
+        if let Some((leader, popped_pool)) =
+            self.workers.push(leader, WorkerPool::new(peer_workers))
+        {
+            return Some(ShutdownWorkerPool {
                 leader,
-                worker: popped_worker,
+                workers: popped_pool.workers,
             });
         }
         None
     }
 
-    pub fn pop(&mut self, leader: SocketAddr) -> Option<ShutdownWorker> {
-        if let Some(popped_worker) = self.workers.pop(&leader) {
-            return Some(ShutdownWorker {
+    pub fn pop(&mut self, leader: SocketAddr) -> Option<ShutdownWorkerPool> {
+        if let Some(popped_pool) = self.workers.pop(&leader) {
+            return Some(ShutdownWorkerPool {
                 leader,
-                worker: popped_worker,
+                workers: popped_pool.workers,
             });
         }
         None
@@ -138,42 +502,146 @@ impl WorkersCache {
     /// [`WorkersCacheError::ShutdownError`]. In case if the worker is not
     /// stopped but it's channel is unexpectedly dropped, it returns
     /// [`WorkersCacheError::ReceiverDropped`].
+    ///
+    /// On [`WorkersCacheError::ReceiverDropped`], the caller should follow up
+    /// with [`Self::maybe_respawn`] for `peer` to rebuild the pool if a
+    /// [`RespawnPolicy`] is configured; this method does not do so itself.
     pub fn try_send_transactions_to_address(
         &mut self,
         peer: &SocketAddr,
         txs_batch: TransactionBatch,
     ) -> Result<(), WorkersCacheError> {
         let Self {
-            workers, cancel, ..
+            workers,
+            cancel,
+            totals,
+            ..
         } = self;
         if cancel.is_cancelled() {
             return Err(WorkersCacheError::ShutdownError);
         }
 
-        let current_worker = workers.get(peer).expect(
+        let current_pool = workers.get_mut(peer).expect(
             "Failed to fetch worker for peer {peer}.\n\
              Peer existence must be checked before this call using `contains` method.",
         );
-        let send_res = current_worker.try_send_transactions(txs_batch);
+        let Some(index) = current_pool.least_loaded() else {
+            return Err(WorkersCacheError::ReceiverDropped);
+        };
+        let send_res = current_pool.workers[index].try_send_transactions(txs_batch);
 
-        if let Err(WorkersCacheError::ReceiverDropped) = send_res {
-            debug!(
-                "Failed to deliver transaction batch for leader {}, drop batch.",
-                peer.ip()
-            );
-            maybe_shutdown_worker(workers.pop(peer).map(|current_worker| ShutdownWorker {
-                leader: *peer,
-                worker: current_worker,
-            }));
+        match &send_res {
+            Err(WorkersCacheError::FullChannel) => {
+                totals
+                    .lock()
+                    .expect("stats mutex poisoned")
+                    .full_channel_drops += 1;
+            }
+            Err(WorkersCacheError::ReceiverDropped) => {
+                totals
+                    .lock()
+                    .expect("stats mutex poisoned")
+                    .receiver_dropped_drops += 1;
+                debug!(
+                    "Failed to deliver transaction batch for leader {}, drop worker.",
+                    peer.ip()
+                );
+                let dead_worker = current_pool.workers.remove(index);
+                if current_pool.workers.is_empty() {
+                    workers.pop(peer);
+                }
+                maybe_shutdown_worker(
+                    Some(ShutdownWorker {
+                        leader: *peer,
+                        worker: dead_worker,
+                    }),
+                    totals.clone(),
+                );
+            }
+            _ => {}
         }
 
         send_res
     }
 
+    /// Attempts to reserve capacity in the worker channel for a given peer
+    /// without blocking and without building a [`TransactionBatch`] first.
+    ///
+    /// On success, the returned [`WorkerPermit`] guarantees that
+    /// [`WorkerPermit::send`] will not fail for capacity reasons, so callers
+    /// can check readiness before doing the work of assembling a batch,
+    /// instead of speculatively calling
+    /// [`Self::try_send_transactions_to_address`] and handling
+    /// [`WorkersCacheError::FullChannel`] after the fact.
+    ///
+    /// If it happens that the peer's worker is stopped, it returns
+    /// [`WorkersCacheError::ShutdownError`]. If the worker is not stopped but
+    /// its receiver has been dropped, the dead worker is evicted and
+    /// [`WorkersCacheError::ReceiverDropped`] is returned, mirroring
+    /// [`Self::try_send_transactions_to_address`]. As with that method, the
+    /// caller should follow up with [`Self::maybe_respawn`] afterward to
+    /// rebuild the pool.
+    pub fn reserve_for(&mut self, peer: &SocketAddr) -> Result<WorkerPermit, WorkersCacheError> {
+        let Self {
+            workers,
+            cancel,
+            totals,
+            ..
+        } = self;
+        if cancel.is_cancelled() {
+            return Err(WorkersCacheError::ShutdownError);
+        }
+
+        let current_pool = workers.get_mut(peer).expect(
+            "Failed to fetch worker for peer {peer}.\n\
+             Peer existence must be checked before this call using `contains` method.",
+        );
+        let Some(index) = current_pool.least_loaded() else {
+            return Err(WorkersCacheError::ReceiverDropped);
+        };
+        let reserve_res = current_pool.workers[index].try_reserve();
+
+        match &reserve_res {
+            Err(WorkersCacheError::FullChannel) => {
+                totals
+                    .lock()
+                    .expect("stats mutex poisoned")
+                    .full_channel_drops += 1;
+            }
+            Err(WorkersCacheError::ReceiverDropped) => {
+                totals
+                    .lock()
+                    .expect("stats mutex poisoned")
+                    .receiver_dropped_drops += 1;
+                debug!(
+                    "Worker's receiver dropped while reserving capacity for leader {}, evict worker.",
+                    peer.ip()
+                );
+                let dead_worker = current_pool.workers.remove(index);
+                if current_pool.workers.is_empty() {
+                    workers.pop(peer);
+                }
+                maybe_shutdown_worker(
+                    Some(ShutdownWorker {
+                        leader: *peer,
+                        worker: dead_worker,
+                    }),
+                    totals.clone(),
+                );
+            }
+            _ => {}
+        }
+
+        reserve_res.map(WorkerPermit::new)
+    }
+
     /// Sends a batch of transactions to the worker for a given peer.
     ///
-    /// If the worker for the peer is disconnected or fails, it
-    /// is removed from the cache.
+    /// If the worker for the peer is disconnected or fails, it is removed
+    /// from the peer's pool, and the pool is removed from the cache once it
+    /// has no workers left. As with [`Self::try_send_transactions_to_address`],
+    /// the caller should follow up with [`Self::maybe_respawn`] afterward to
+    /// rebuild the pool.
     #[allow(
         dead_code,
         reason = "This method will be used in the upcoming changes to implement optional backpressure on the sender."
@@ -184,21 +652,40 @@ impl WorkersCache {
         txs_batch: TransactionBatch,
     ) -> Result<(), WorkersCacheError> {
         let Self {
-            workers, cancel, ..
+            workers,
+            cancel,
+            totals,
+            ..
         } = self;
 
         let body = async move {
-            let current_worker = workers.get(peer).expect(
+            let current_pool = workers.get_mut(peer).expect(
                 "Failed to fetch worker for peer {peer}.\n\
                  Peer existence must be checked before this call using `contains` method.",
             );
-            let send_res = current_worker.send_transactions(txs_batch).await;
+            let Some(index) = current_pool.least_loaded() else {
+                return Err(WorkersCacheError::ReceiverDropped);
+            };
+            let send_res = current_pool.workers[index]
+                .send_transactions(txs_batch)
+                .await;
             if let Err(WorkersCacheError::ReceiverDropped) = send_res {
-                // Remove the worker from the cache, if the peer has disconnected.
-                maybe_shutdown_worker(workers.pop(peer).map(|current_worker| ShutdownWorker {
-                    leader: *peer,
-                    worker: current_worker,
-                }));
+                totals
+                    .lock()
+                    .expect("stats mutex poisoned")
+                    .receiver_dropped_drops += 1;
+                // Remove the worker from the pool, if the peer has disconnected.
+                let dead_worker = current_pool.workers.remove(index);
+                if current_pool.workers.is_empty() {
+                    workers.pop(peer);
+                }
+                maybe_shutdown_worker(
+                    Some(ShutdownWorker {
+                        leader: *peer,
+                        worker: dead_worker,
+                    }),
+                    totals.clone(),
+                );
             }
 
             send_res
@@ -212,22 +699,63 @@ impl WorkersCache {
 
     /// Closes and removes all workers in the cache. This is typically done when
     /// shutting down the system.
-    pub(crate) async fn shutdown(&mut self) {
-        // Interrupt any outstanding `send_transactions()` calls.
-        self.cancel.cancel();
+    ///
+    /// This is a two-phase shutdown: each worker first drains whatever
+    /// batches are already queued in its channel, up to `drain_timeout`, so
+    /// already-accepted transactions aren't silently dropped at teardown.
+    /// Only workers that fail to drain in time are force-cancelled. Every
+    /// peer's pool is drained concurrently, so the deadline is `drain_timeout`
+    /// overall rather than `drain_timeout` per peer.
+    pub(crate) async fn shutdown(&mut self, drain_timeout: Duration) -> ShutdownSummary {
+        let mut pools = Vec::with_capacity(self.workers.len());
+        while let Some((leader, pool)) = self.workers.pop_lru() {
+            pools.push(ShutdownWorkerPool {
+                leader,
+                workers: pool.workers,
+            });
+        }
 
-        while let Some((leader, worker)) = self.workers.pop_lru() {
-            let res = worker.shutdown().await;
-            if let Err(err) = res {
-                debug!("Error while shutting down worker for {leader}: {err}");
+        let outcomes = join_all(pools.into_iter().map(|pool| async move {
+            let leader = pool.leader();
+            (leader, pool.drain(drain_timeout).await)
+        }))
+        .await;
+
+        let mut summary = ShutdownSummary::default();
+        for (leader, outcome) in outcomes {
+            summary.drained += outcome.drained;
+            summary.force_cancelled += outcome.force_cancelled;
+            if outcome.force_cancelled > 0 {
+                debug!(
+                    "Force-cancelled {} worker(s) for {leader} after drain timeout",
+                    outcome.force_cancelled
+                );
             }
+            self.totals
+                .lock()
+                .expect("stats mutex poisoned")
+                .merge(&outcome.stats);
         }
+
+        // Interrupt any stragglers still waiting on cache-level operations.
+        self.cancel.cancel();
+        summary
     }
 }
 
-/// [`ShutdownWorker`] takes care of stopping the worker. It's method
-/// `shutdown()` should be executed in a separate task to hide the latency of
-/// finishing worker gracefully.
+/// Summary of a [`WorkersCache::shutdown`] call: how many workers drained
+/// their queued batches cleanly versus were force-cancelled after
+/// `drain_timeout` elapsed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ShutdownSummary {
+    pub drained: usize,
+    pub force_cancelled: usize,
+}
+
+/// [`ShutdownWorker`] takes care of stopping a single worker evicted from its
+/// pool (e.g. after its receiver was dropped), leaving the rest of the pool
+/// untouched. Its method `shutdown()` should be executed in a separate task
+/// to hide the latency of finishing the worker gracefully.
 pub struct ShutdownWorker {
     leader: SocketAddr,
     worker: WorkerInfo,
@@ -238,20 +766,355 @@ impl ShutdownWorker {
         self.leader
     }
 
-    pub(crate) async fn shutdown(self) -> Result<(), WorkersCacheError> {
+    pub(crate) async fn shutdown(self) -> Result<WorkerStats, WorkersCacheError> {
         self.worker.shutdown().await
     }
 }
 
-pub fn maybe_shutdown_worker(worker: Option<ShutdownWorker>) {
+pub fn maybe_shutdown_worker(worker: Option<ShutdownWorker>, totals: SharedWorkerStats) {
     let Some(worker) = worker else {
         return;
     };
     tokio::spawn(async move {
         let leader = worker.leader();
-        let res = worker.shutdown().await;
-        if let Err(err) = res {
-            debug!("Error while shutting down worker for {leader}: {err}");
+        match worker.shutdown().await {
+            Ok(stats) => totals.lock().expect("stats mutex poisoned").merge(&stats),
+            Err(err) => debug!("Error while shutting down worker for {leader}: {err}"),
         }
     });
 }
+
+/// [`ShutdownWorkerPool`] takes care of stopping every worker belonging to a
+/// peer at once, used when the whole pool is evicted from the cache (LRU
+/// eviction, resize, or full cache shutdown) rather than just one dead
+/// worker within it.
+pub struct ShutdownWorkerPool {
+    leader: SocketAddr,
+    workers: Vec<WorkerInfo>,
+}
+
+impl ShutdownWorkerPool {
+    pub(crate) fn leader(&self) -> SocketAddr {
+        self.leader
+    }
+
+    pub(crate) async fn shutdown(self) -> WorkerStats {
+        let mut totals = WorkerStats::default();
+        for worker in self.workers {
+            match worker.shutdown().await {
+                Ok(stats) => totals.merge(&stats),
+                Err(err) => debug!(
+                    "Error while shutting down pooled worker for {}: {err}",
+                    self.leader
+                ),
+            }
+        }
+        totals
+    }
+
+    /// Drains every worker in the pool concurrently, so the pool's overall
+    /// deadline stays `drain_timeout` regardless of fan-out, rather than
+    /// `fan_out * drain_timeout` for a sequential drain.
+    async fn drain(self, drain_timeout: Duration) -> PoolDrainOutcome {
+        let worker_outcomes = join_all(
+            self.workers
+                .into_iter()
+                .map(|worker| worker.drain(drain_timeout)),
+        )
+        .await;
+
+        let mut outcome = PoolDrainOutcome::default();
+        for worker_outcome in worker_outcomes {
+            if worker_outcome.force_cancelled {
+                outcome.force_cancelled += 1;
+            } else {
+                outcome.drained += 1;
+            }
+            if let Some(stats) = worker_outcome.stats {
+                outcome.stats.merge(&stats);
+            }
+        }
+        outcome
+    }
+}
+
+/// Outcome of a [`ShutdownWorkerPool::drain`] call, aggregated across every
+/// worker in the pool.
+#[derive(Default)]
+struct PoolDrainOutcome {
+    stats: WorkerStats,
+    drained: usize,
+    force_cancelled: usize,
+}
+
+pub fn maybe_shutdown_worker_pool(pool: Option<ShutdownWorkerPool>, totals: SharedWorkerStats) {
+    let Some(pool) = pool else {
+        return;
+    };
+    tokio::spawn(async move {
+        let leader = pool.leader();
+        let stats = pool.shutdown().await;
+        debug!("Shut down evicted worker pool for {leader}");
+        totals.lock().expect("stats mutex poisoned").merge(&stats);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    /// Builds a [`WorkerInfo`] wired to `sender`, with a no-op handle and a
+    /// stats channel whose sender is dropped immediately (tests here only
+    /// care about channel-capacity/readiness behavior, not worker stats).
+    fn test_worker_info(sender: mpsc::Sender<TransactionBatch>) -> WorkerInfo {
+        let (_stats_tx, stats_rx) = oneshot::channel();
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 1,
+            worker_channel_capacity: 1,
+        });
+        WorkerInfo::new(
+            sender,
+            tokio::spawn(async {}),
+            CancellationToken::new(),
+            config_rx,
+            stats_rx,
+        )
+    }
+
+    #[tokio::test]
+    async fn try_reserve_reports_full_channel_then_receiver_dropped() {
+        let (sender, receiver) = mpsc::channel::<TransactionBatch>(1);
+        let worker = test_worker_info(sender);
+
+        let permit = worker
+            .try_reserve()
+            .expect("first reservation should succeed");
+        assert_eq!(
+            worker.try_reserve().unwrap_err(),
+            WorkersCacheError::FullChannel
+        );
+
+        drop(permit);
+        drop(receiver);
+        assert_eq!(
+            worker.try_reserve().unwrap_err(),
+            WorkersCacheError::ReceiverDropped
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_config_updates_evicts_lru_pool_on_shrink() {
+        let (config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 2,
+            worker_channel_capacity: 4,
+        });
+        let mut cache = WorkersCache::new(config_rx, CancellationToken::new(), 1);
+
+        cache.push(addr(1), vec![test_worker_info(mpsc::channel(1).0)]);
+        cache.push(addr(2), vec![test_worker_info(mpsc::channel(1).0)]);
+        assert_eq!(cache.workers.len(), 2);
+
+        config_tx.send_modify(|config| config.lru_capacity = 1);
+        cache
+            .apply_config_updates()
+            .await
+            .expect("config watch sender is still alive");
+
+        assert_eq!(cache.workers.len(), 1);
+        assert!(
+            !cache.contains(&addr(1)),
+            "the least recently used pool should have been evicted"
+        );
+        assert!(cache.contains(&addr(2)));
+    }
+
+    #[test]
+    fn next_backoff_doubles_and_caps_at_max() {
+        let max_backoff = Duration::from_millis(400);
+        assert_eq!(
+            next_backoff(Duration::from_millis(50), max_backoff),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            next_backoff(Duration::from_millis(300), max_backoff),
+            Duration::from_millis(400)
+        );
+        assert_eq!(
+            next_backoff(Duration::from_millis(400), max_backoff),
+            Duration::from_millis(400)
+        );
+    }
+
+    #[tokio::test]
+    async fn maybe_respawn_fills_pool_up_to_fan_out() {
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 4,
+            worker_channel_capacity: 4,
+        });
+        let fan_out = 2;
+        let mut cache = WorkersCache::new(config_rx, CancellationToken::new(), fan_out);
+        let peer = addr(3);
+
+        let factory: WorkerFactory =
+            Arc::new(|_peer| Box::pin(async move { test_worker_info(mpsc::channel(4).0) }));
+        cache.set_respawn_policy(RespawnPolicy::new(
+            factory,
+            Duration::from_secs(60),
+            Duration::from_secs(300),
+            3,
+        ));
+
+        assert!(
+            cache.maybe_respawn(peer).await,
+            "an absent pool below fan_out should be spawned"
+        );
+        assert_eq!(cache.workers.get(&peer).unwrap().len(), fan_out);
+
+        assert!(
+            !cache.maybe_respawn(peer).await,
+            "a pool already at fan_out should not be touched"
+        );
+    }
+
+    #[test]
+    fn worker_stats_merge_sums_every_field() {
+        let mut totals = WorkerStats {
+            batches_sent: 1,
+            transactions_sent: 2,
+            full_channel_drops: 3,
+            receiver_dropped_drops: 4,
+            bytes_sent: 5,
+        };
+        totals.merge(&WorkerStats {
+            batches_sent: 10,
+            transactions_sent: 20,
+            full_channel_drops: 30,
+            receiver_dropped_drops: 40,
+            bytes_sent: 50,
+        });
+
+        assert_eq!(totals.batches_sent, 11);
+        assert_eq!(totals.transactions_sent, 22);
+        assert_eq!(totals.full_channel_drops, 33);
+        assert_eq!(totals.receiver_dropped_drops, 44);
+        assert_eq!(totals.bytes_sent, 55);
+    }
+
+    #[tokio::test]
+    async fn reserve_for_counts_full_channel_and_receiver_dropped_drops() {
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 1,
+            worker_channel_capacity: 1,
+        });
+        let mut cache = WorkersCache::new(config_rx, CancellationToken::new(), 1);
+        let peer = addr(4);
+        let (sender, receiver) = mpsc::channel::<TransactionBatch>(1);
+        cache.push(peer, vec![test_worker_info(sender)]);
+
+        let permit = cache
+            .reserve_for(&peer)
+            .expect("first reservation should succeed");
+        assert_eq!(
+            cache.reserve_for(&peer).unwrap_err(),
+            WorkersCacheError::FullChannel
+        );
+        assert_eq!(cache.drain_stats().full_channel_drops, 1);
+
+        drop(permit);
+        drop(receiver);
+        assert_eq!(
+            cache.reserve_for(&peer).unwrap_err(),
+            WorkersCacheError::ReceiverDropped
+        );
+        assert_eq!(cache.drain_stats().receiver_dropped_drops, 1);
+    }
+
+    #[tokio::test]
+    async fn drain_completes_cleanly_when_worker_finishes_in_time() {
+        let (sender, _receiver) = mpsc::channel::<TransactionBatch>(1);
+        let (stats_tx, stats_rx) = oneshot::channel();
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 1,
+            worker_channel_capacity: 1,
+        });
+        let cancel = CancellationToken::new();
+        // The worker task owns the stats sender and reports on completion,
+        // so `drain` can collect its stats once the task has finished.
+        let worker = WorkerInfo::new(
+            sender,
+            tokio::spawn(async move {
+                let _ = stats_tx.send(WorkerStats::default());
+            }),
+            cancel.clone(),
+            config_rx,
+            stats_rx,
+        );
+
+        let outcome = worker.drain(Duration::from_millis(200)).await;
+
+        assert!(!outcome.force_cancelled);
+        assert!(!cancel.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn drain_force_cancels_after_timeout_elapses() {
+        let (sender, _receiver) = mpsc::channel::<TransactionBatch>(1);
+        let (_stats_tx, stats_rx) = oneshot::channel();
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 1,
+            worker_channel_capacity: 1,
+        });
+        let cancel = CancellationToken::new();
+        let cancel_in_task = cancel.clone();
+        // Never finishes on its own; only stops once `drain` cancels it after
+        // the timeout elapses.
+        let handle = tokio::spawn(async move { cancel_in_task.cancelled().await });
+        let worker = WorkerInfo::new(sender, handle, cancel.clone(), config_rx, stats_rx);
+
+        let outcome = worker.drain(Duration::from_millis(20)).await;
+
+        assert!(outcome.force_cancelled);
+        assert!(cancel.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn least_loaded_round_robins_between_tied_workers() {
+        let mut pool = WorkerPool::new(vec![
+            test_worker_info(mpsc::channel(2).0),
+            test_worker_info(mpsc::channel(2).0),
+            test_worker_info(mpsc::channel(1).0),
+        ]);
+
+        // Workers 0 and 1 both have the most spare capacity; ties should
+        // round-robin between them instead of always favoring the first.
+        assert_eq!(pool.least_loaded(), Some(0));
+        assert_eq!(pool.least_loaded(), Some(1));
+        assert_eq!(pool.least_loaded(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn pool_is_evicted_once_its_last_worker_dies() {
+        let (_config_tx, config_rx) = watch::channel(WorkersConfig {
+            lru_capacity: 1,
+            worker_channel_capacity: 1,
+        });
+        let mut cache = WorkersCache::new(config_rx, CancellationToken::new(), 1);
+        let peer = addr(5);
+        let (sender, receiver) = mpsc::channel::<TransactionBatch>(1);
+        cache.push(peer, vec![test_worker_info(sender)]);
+        drop(receiver);
+
+        assert_eq!(
+            cache.reserve_for(&peer).unwrap_err(),
+            WorkersCacheError::ReceiverDropped
+        );
+        assert!(
+            !cache.contains(&peer),
+            "the now-empty pool should be evicted rather than left as a zombie LRU entry"
+        );
+    }
+}